@@ -0,0 +1,208 @@
+use tokio_postgres::{Client, NoTls, Config};
+use tokio_postgres::config::ChannelBinding;
+use std::env;
+
+/// Get PostgreSQL connection configuration from environment
+fn get_connection_config() -> Config {
+    let host = env::var("PGWIRE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("PGWIRE_PORT")
+        .unwrap_or_else(|_| "5432".to_string())
+        .parse::<u16>()
+        .unwrap_or(5432);
+    let dbname = env::var("PGWIRE_DATABASE").unwrap_or_else(|_| "USER".to_string());
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    let mut config = Config::new();
+    config
+        .host(&host)
+        .port(port)
+        .dbname(&dbname)
+        .user(&user)
+        .password(&password);
+
+    config
+}
+
+/// Connect with an explicit user/password pair, letting tokio-postgres pick
+/// whichever authentication flow (plain, MD5, or SCRAM-SHA-256) the server
+/// advertises in its `Authentication*` message.
+async fn connect_as(user: &str, password: &str) -> Result<Client, Box<dyn std::error::Error>> {
+    let mut config = get_connection_config();
+    config.user(user).password(password);
+
+    let (client, connection) = config.connect(NoTls).await?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+/// Connect as `user`, pinning the client's channel-binding policy. The
+/// authentication mechanism is still the one the server requests for `user`;
+/// the policy only decides whether the client accepts a handshake that does
+/// not bind to a TLS channel.
+async fn connect_with(
+    user: &str,
+    password: &str,
+    channel_binding: ChannelBinding,
+) -> Result<Client, Box<dyn std::error::Error>> {
+    let mut config = get_connection_config();
+    config.user(user).password(password).channel_binding(channel_binding);
+
+    let (client, connection) = config.connect(NoTls).await?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+/// Server user provisioned to authenticate via MD5.
+fn md5_user() -> String {
+    env::var("PGWIRE_MD5_USERNAME").unwrap_or_else(|_| "md5_user".to_string())
+}
+
+/// Server user provisioned to authenticate via SCRAM-SHA-256.
+fn scram_user() -> String {
+    env::var("PGWIRE_SCRAM_USERNAME").unwrap_or_else(|_| "scram_user".to_string())
+}
+
+#[tokio::test]
+async fn test_auth_with_valid_credentials() {
+    // GIVEN: The configured credentials
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    // WHEN: Authenticating via whichever flow the server requests
+    let client = connect_as(&user, &password)
+        .await
+        .expect("should authenticate with valid credentials");
+
+    // THEN: The session should be usable
+    let row = client.query_one("SELECT 1", &[]).await.expect("should query");
+    let result: i32 = row.get(0);
+    assert_eq!(result, 1);
+}
+
+#[tokio::test]
+async fn test_auth_rejects_wrong_password() {
+    // GIVEN: A valid user with an incorrect password
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+
+    // WHEN: Completing the password/SCRAM exchange with a bad secret
+    let result = connect_as(&user, "definitely-not-the-password").await;
+
+    // THEN: Authentication should fail (ErrorResponse with INVALID_PASSWORD)
+    assert!(result.is_err(), "wrong password should be rejected");
+    if let Err(e) = result {
+        let msg = e.to_string().to_lowercase();
+        assert!(
+            msg.contains("password") || msg.contains("authentication"),
+            "error should indicate an authentication failure: {}",
+            msg
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_auth_rejects_unknown_user() {
+    // GIVEN: A user that does not exist
+    // WHEN: Attempting to authenticate
+    let result = connect_as("no_such_user", "test").await;
+
+    // THEN: The connection should be refused
+    assert!(result.is_err(), "unknown user should be rejected");
+}
+
+#[tokio::test]
+async fn test_auth_md5_path() {
+    // GIVEN: A user the server authenticates with AuthenticationMD5Password
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    // WHEN: Authenticating with channel binding disabled (MD5 carries none)
+    let client = connect_with(&md5_user(), &password, ChannelBinding::Disable)
+        .await
+        .expect("should complete the MD5 password exchange");
+
+    // THEN: The MD5-authenticated session should be usable
+    let row = client.query_one("SELECT 1", &[]).await.expect("should query");
+    assert_eq!(row.get::<_, i32>(0), 1);
+}
+
+#[tokio::test]
+async fn test_auth_scram_path() {
+    // GIVEN: A user the server authenticates with AuthenticationSASL (SCRAM)
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    // WHEN: Driving the full SCRAM-SHA-256 client-final/server-final exchange
+    let client = connect_with(&scram_user(), &password, ChannelBinding::Prefer)
+        .await
+        .expect("should complete the SCRAM-SHA-256 exchange");
+
+    // THEN: The SCRAM-authenticated session should be usable
+    let row = client.query_one("SELECT 1", &[]).await.expect("should query");
+    assert_eq!(row.get::<_, i32>(0), 1);
+}
+
+#[tokio::test]
+async fn test_auth_scram_requires_channel_binding_over_plaintext() {
+    // GIVEN: A SCRAM user and a client that insists on channel binding
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    // WHEN: Forcing ChannelBinding::Require on a plaintext (NoTls) connection,
+    // where no channel to bind to exists
+    let result = connect_with(&scram_user(), &password, ChannelBinding::Require).await;
+
+    // THEN: The client must refuse to proceed. With channel binding required,
+    // tokio-postgres rejects every handshake that does not bind to a TLS
+    // channel (cleartext, MD5 or unbound SCRAM alike), and NoTls has none.
+    assert!(
+        result.is_err(),
+        "SCRAM with required channel binding must fail without TLS"
+    );
+}
+
+#[tokio::test]
+async fn test_auth_session_reuse() {
+    // GIVEN: A successfully authenticated session
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+    let client = connect_as(&user, &password).await.expect("should authenticate");
+
+    // WHEN: Issuing several queries after the handshake completes
+    let row1 = client.query_one("SELECT 1", &[]).await.expect("query 1");
+    let row2 = client.query_one("SELECT 2", &[]).await.expect("query 2");
+
+    // THEN: The authenticated session should remain valid
+    assert_eq!(row1.get::<_, i32>(0), 1);
+    assert_eq!(row2.get::<_, i32>(0), 2);
+}
+
+#[tokio::test]
+async fn test_auth_multiple_sessions() {
+    // GIVEN: Valid credentials
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    // WHEN: Opening several independent authenticated connections
+    for i in 1..=3 {
+        let client = connect_as(&user, &password)
+            .await
+            .expect("should authenticate each session");
+
+        // THEN: Each negotiates its own salt/nonce and works independently
+        let row = client
+            .query_one("SELECT $1::int4", &[&i])
+            .await
+            .expect("should execute query");
+        assert_eq!(row.get::<_, i32>(0), i);
+    }
+}
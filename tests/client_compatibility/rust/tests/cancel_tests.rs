@@ -0,0 +1,122 @@
+use tokio_postgres::{Client, NoTls, Config};
+use tokio_postgres::error::SqlState;
+use std::env;
+use std::time::{Duration, Instant};
+
+/// Get PostgreSQL connection configuration from environment
+fn get_connection_config() -> Config {
+    let host = env::var("PGWIRE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("PGWIRE_PORT")
+        .unwrap_or_else(|_| "5432".to_string())
+        .parse::<u16>()
+        .unwrap_or(5432);
+    let dbname = env::var("PGWIRE_DATABASE").unwrap_or_else(|_| "USER".to_string());
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    let mut config = Config::new();
+    config
+        .host(&host)
+        .port(port)
+        .dbname(&dbname)
+        .user(&user)
+        .password(&password);
+
+    config
+}
+
+async fn connect() -> Result<Client, Box<dyn std::error::Error>> {
+    let config = get_connection_config();
+    let (client, connection) = config.connect(NoTls).await?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+#[tokio::test]
+async fn test_cancel_token_is_available() {
+    // GIVEN: A connected client
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Obtaining a cancel token (built from the BackendKeyData
+    // pid/secret the server sent at startup)
+    let cancel_token = client.cancel_token();
+
+    // THEN: Cancelling with nothing running is a harmless no-op
+    cancel_token
+        .cancel_query(NoTls)
+        .await
+        .expect("CancelRequest should be accepted by the server");
+}
+
+#[tokio::test]
+async fn test_cancel_in_flight_query() {
+    // GIVEN: A client running a slow query and its cancel token
+    let client = connect().await.expect("should connect");
+    let cancel_token = client.cancel_token();
+
+    // WHEN: The query is launched and then cancelled out-of-band
+    let started = Instant::now();
+    let query = tokio::spawn(async move {
+        // A long-running statement that the cancellation should interrupt.
+        client
+            .batch_execute("CALL %SYSTEM.SQL.Sleep(30)")
+            .await
+    });
+
+    // Give the backend a moment to begin executing before cancelling.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    cancel_token
+        .cancel_query(NoTls)
+        .await
+        .expect("should deliver CancelRequest");
+
+    // THEN: The in-flight query should come back with a query-canceled error,
+    // and well before the statement's own 30s runtime would elapse. Asserting
+    // the SQLSTATE (rather than merely `is_err()`) ensures the failure is the
+    // cancellation and not an unrelated error that would pass trivially.
+    let result = query.await.expect("query task should complete");
+    let elapsed = started.elapsed();
+    let err = result.expect_err("cancelled query should return an error");
+    let db_err = err
+        .as_db_error()
+        .expect("cancellation should surface a database error");
+    assert_eq!(
+        db_err.code(),
+        &SqlState::QUERY_CANCELED,
+        "a cancelled query should fail with 57014 (query_canceled), got {:?}: {}",
+        db_err.code(),
+        db_err.message()
+    );
+    assert!(
+        elapsed < Duration::from_secs(10),
+        "cancellation should interrupt the 30s sleep promptly, but the query \
+         ran for {:?}",
+        elapsed
+    );
+}
+
+#[tokio::test]
+async fn test_connection_survives_cancel() {
+    // GIVEN: A client that has sent a CancelRequest with no query running
+    let client = connect().await.expect("should connect");
+    let cancel_token = client.cancel_token();
+    cancel_token
+        .cancel_query(NoTls)
+        .await
+        .expect("should accept CancelRequest");
+
+    // WHEN: Reusing the same session afterwards
+    let row = client
+        .query_one("SELECT 1", &[])
+        .await
+        .expect("session should stay usable after a cancel");
+
+    // THEN: The connection remains healthy
+    assert_eq!(row.get::<_, i32>(0), 1);
+}
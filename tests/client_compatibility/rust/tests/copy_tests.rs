@@ -0,0 +1,192 @@
+use tokio_postgres::{Client, NoTls, Config};
+use tokio_postgres::binary_copy::{BinaryCopyInWriter, BinaryCopyOutStream};
+use tokio_postgres::types::Type;
+use futures::{pin_mut, SinkExt, TryStreamExt};
+use std::env;
+
+/// Get PostgreSQL connection configuration from environment
+fn get_connection_config() -> Config {
+    let host = env::var("PGWIRE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("PGWIRE_PORT")
+        .unwrap_or_else(|_| "5432".to_string())
+        .parse::<u16>()
+        .unwrap_or(5432);
+    let dbname = env::var("PGWIRE_DATABASE").unwrap_or_else(|_| "USER".to_string());
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    let mut config = Config::new();
+    config
+        .host(&host)
+        .port(port)
+        .dbname(&dbname)
+        .user(&user)
+        .password(&password);
+
+    config
+}
+
+async fn connect() -> Result<Client, Box<dyn std::error::Error>> {
+    let config = get_connection_config();
+    let (client, connection) = config.connect(NoTls).await?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+/// COPY cannot be exercised without a target table, and the baseline suite
+/// documents that DDL/INSERT is not generally supported through
+/// tokio-postgres against IRIS PGWire. These round-trips therefore only run
+/// when the server under test is explicitly declared DDL-capable via
+/// `PGWIRE_DDL_SUPPORTED=1`; otherwise they skip rather than panic at setup.
+fn ddl_supported() -> bool {
+    matches!(env::var("PGWIRE_DDL_SUPPORTED").as_deref(), Ok("1") | Ok("true"))
+}
+
+/// Create a fresh scratch table for a COPY round-trip, ignoring any
+/// leftover from a previous run.
+async fn prepare_table(client: &Client, name: &str) {
+    let _ = client
+        .batch_execute(&format!("DROP TABLE {}", name))
+        .await;
+    client
+        .batch_execute(&format!("CREATE TABLE {} (id int4, label varchar(64))", name))
+        .await
+        .expect("should create scratch table");
+}
+
+#[tokio::test]
+async fn test_copy_in_text_format() {
+    if !ddl_supported() {
+        eprintln!("skipping: set PGWIRE_DDL_SUPPORTED=1 to run COPY fixtures");
+        return;
+    }
+
+    // GIVEN: A client and a scratch table
+    let client = connect().await.expect("should connect");
+    prepare_table(&client, "copy_text_in").await;
+
+    // WHEN: Streaming rows via COPY ... FROM STDIN in text format
+    let sink = client
+        .copy_in("COPY copy_text_in (id, label) FROM STDIN")
+        .await
+        .expect("server should reply with CopyInResponse");
+    pin_mut!(sink);
+    sink.send(bytes::Bytes::from_static(b"1\tfirst\n2\tsecond\n"))
+        .await
+        .expect("should stream CopyData");
+    let written = sink.finish().await.expect("should send CopyDone");
+
+    // THEN: Both rows should have been inserted
+    assert_eq!(written, 2, "COPY should report two rows loaded");
+    let count = client
+        .query_one("SELECT COUNT(*) FROM copy_text_in", &[])
+        .await
+        .expect("should count rows");
+    assert_eq!(count.get::<_, i64>(0), 2);
+}
+
+#[tokio::test]
+async fn test_copy_out_text_format() {
+    if !ddl_supported() {
+        eprintln!("skipping: set PGWIRE_DDL_SUPPORTED=1 to run COPY fixtures");
+        return;
+    }
+
+    // GIVEN: A scratch table seeded with rows
+    let client = connect().await.expect("should connect");
+    prepare_table(&client, "copy_text_out").await;
+    client
+        .batch_execute("INSERT INTO copy_text_out (id, label) VALUES (7, 'seven')")
+        .await
+        .expect("should seed row");
+
+    // WHEN: Draining the table via COPY ... TO STDOUT
+    let stream = client
+        .copy_out("COPY copy_text_out TO STDOUT")
+        .await
+        .expect("server should reply with CopyOutResponse");
+    let rows: Vec<bytes::Bytes> = stream.try_collect().await.expect("should collect CopyData");
+
+    // THEN: The emitted CopyData should contain the seeded row
+    let payload: Vec<u8> = rows.concat();
+    let text = String::from_utf8_lossy(&payload);
+    assert!(text.contains("seven"), "COPY TO output should contain the row: {}", text);
+}
+
+#[tokio::test]
+async fn test_binary_copy_in() {
+    if !ddl_supported() {
+        eprintln!("skipping: set PGWIRE_DDL_SUPPORTED=1 to run COPY fixtures");
+        return;
+    }
+
+    // GIVEN: A scratch table
+    let client = connect().await.expect("should connect");
+    prepare_table(&client, "copy_binary_in").await;
+
+    // WHEN: Streaming rows via the binary COPY format (PGCOPY signature)
+    let sink = client
+        .copy_in("COPY copy_binary_in (id, label) FROM STDIN BINARY")
+        .await
+        .expect("should begin binary COPY IN");
+    let types = [Type::INT4, Type::VARCHAR];
+    let writer = BinaryCopyInWriter::new(sink, &types);
+    pin_mut!(writer);
+    writer
+        .as_mut()
+        .write(&[&10i32, &"ten"])
+        .await
+        .expect("should write binary row");
+    writer
+        .as_mut()
+        .write(&[&20i32, &"twenty"])
+        .await
+        .expect("should write binary row");
+    let written = writer.finish().await.expect("should finish binary COPY");
+
+    // THEN: The binary rows should be loaded
+    assert_eq!(written, 2);
+    let count = client
+        .query_one("SELECT COUNT(*) FROM copy_binary_in", &[])
+        .await
+        .expect("should count rows");
+    assert_eq!(count.get::<_, i64>(0), 2);
+}
+
+#[tokio::test]
+async fn test_binary_copy_out() {
+    if !ddl_supported() {
+        eprintln!("skipping: set PGWIRE_DDL_SUPPORTED=1 to run COPY fixtures");
+        return;
+    }
+
+    // GIVEN: A scratch table seeded with one row
+    let client = connect().await.expect("should connect");
+    prepare_table(&client, "copy_binary_out").await;
+    client
+        .batch_execute("INSERT INTO copy_binary_out (id, label) VALUES (99, 'ninety-nine')")
+        .await
+        .expect("should seed row");
+
+    // WHEN: Reading the table back with the binary COPY OUT format
+    let stream = client
+        .copy_out("COPY copy_binary_out TO STDOUT BINARY")
+        .await
+        .expect("should begin binary COPY OUT");
+    let types = [Type::INT4, Type::VARCHAR];
+    let rows: Vec<_> = BinaryCopyOutStream::new(stream, &types)
+        .try_collect()
+        .await
+        .expect("should collect binary rows");
+
+    // THEN: The decoded binary values should match what was seeded
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<i32>(0), 99);
+    assert_eq!(rows[0].get::<&str>(1), "ninety-nine");
+}
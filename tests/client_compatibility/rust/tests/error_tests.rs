@@ -0,0 +1,175 @@
+use tokio_postgres::{Client, NoTls, Config};
+use tokio_postgres::error::SqlState;
+use std::env;
+
+/// Get PostgreSQL connection configuration from environment
+fn get_connection_config() -> Config {
+    let host = env::var("PGWIRE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("PGWIRE_PORT")
+        .unwrap_or_else(|_| "5432".to_string())
+        .parse::<u16>()
+        .unwrap_or(5432);
+    let dbname = env::var("PGWIRE_DATABASE").unwrap_or_else(|_| "USER".to_string());
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    let mut config = Config::new();
+    config
+        .host(&host)
+        .port(port)
+        .dbname(&dbname)
+        .user(&user)
+        .password(&password);
+
+    config
+}
+
+/// The unique-violation test has to provision a table with a constraint and
+/// seed a row, i.e. DDL + INSERT — which the baseline suite documents as
+/// unsupported through tokio-postgres against IRIS PGWire. It therefore only
+/// runs when the server is explicitly declared DDL-capable via
+/// `PGWIRE_DDL_SUPPORTED=1`; otherwise it skips rather than panic at setup.
+fn ddl_supported() -> bool {
+    matches!(env::var("PGWIRE_DDL_SUPPORTED").as_deref(), Ok("1") | Ok("true"))
+}
+
+async fn connect() -> Result<Client, Box<dyn std::error::Error>> {
+    let config = get_connection_config();
+    let (client, connection) = config.connect(NoTls).await?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+#[tokio::test]
+async fn test_syntax_error_sqlstate() {
+    // GIVEN: A connected client
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Executing a statement that cannot be parsed
+    let err = client
+        .batch_execute("SELECT FROM WHERE")
+        .await
+        .expect_err("malformed SQL should fail");
+
+    // THEN: The ErrorResponse should carry SQLSTATE 42601 (SYNTAX_ERROR)
+    let db_err = err.as_db_error().expect("should be a database error");
+    assert_eq!(
+        db_err.code(),
+        &SqlState::SYNTAX_ERROR,
+        "syntax errors should map to 42601, got {:?}: {}",
+        db_err.code(),
+        db_err.message()
+    );
+}
+
+#[tokio::test]
+async fn test_undefined_table_sqlstate() {
+    // GIVEN: A connected client
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Querying a table that does not exist
+    let err = client
+        .query("SELECT * FROM no_such_table_xyz", &[])
+        .await
+        .expect_err("querying a missing table should fail");
+
+    // THEN: SQLSTATE 42P01 (UNDEFINED_TABLE) should be reported
+    let db_err = err.as_db_error().expect("should be a database error");
+    assert_eq!(
+        db_err.code(),
+        &SqlState::UNDEFINED_TABLE,
+        "missing tables should map to 42P01, got {:?}",
+        db_err.code()
+    );
+}
+
+#[tokio::test]
+async fn test_unique_violation_sqlstate() {
+    if !ddl_supported() {
+        eprintln!("skipping: set PGWIRE_DDL_SUPPORTED=1 to exercise UNIQUE_VIOLATION");
+        return;
+    }
+
+    // GIVEN: A table with a unique/primary key constraint
+    let client = connect().await.expect("should connect");
+    let _ = client.batch_execute("DROP TABLE uniq_probe").await;
+    client
+        .batch_execute("CREATE TABLE uniq_probe (id int4 PRIMARY KEY)")
+        .await
+        .expect("should create table");
+    client
+        .batch_execute("INSERT INTO uniq_probe (id) VALUES (1)")
+        .await
+        .expect("should insert first row");
+
+    // WHEN: Inserting a duplicate key
+    let err = client
+        .batch_execute("INSERT INTO uniq_probe (id) VALUES (1)")
+        .await
+        .expect_err("duplicate key should fail");
+
+    // THEN: SQLSTATE 23505 (UNIQUE_VIOLATION) should be reported
+    let db_err = err.as_db_error().expect("should be a database error");
+    assert_eq!(
+        db_err.code(),
+        &SqlState::UNIQUE_VIOLATION,
+        "duplicate keys should map to 23505, got {:?}",
+        db_err.code()
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_password_sqlstate() {
+    // GIVEN: A valid user with the wrong password
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let mut config = get_connection_config();
+    config.user(&user).password("wrong-password");
+
+    // WHEN: Attempting to authenticate
+    // (the Ok variant carries a Connection, which is not Debug, so match
+    // rather than expect_err here.)
+    let err = match config.connect(NoTls).await {
+        Ok(_) => panic!("bad password should fail"),
+        Err(e) => e,
+    };
+
+    // THEN: SQLSTATE 28P01 (INVALID_PASSWORD) should be reported
+    let db_err = err.as_db_error().expect("should be a database error");
+    assert_eq!(
+        db_err.code(),
+        &SqlState::INVALID_PASSWORD,
+        "auth failure should map to 28P01, got {:?}",
+        db_err.code()
+    );
+}
+
+#[tokio::test]
+async fn test_error_fields_populated() {
+    // GIVEN: A connected client
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Triggering a syntax error
+    let err = client
+        .batch_execute("SELCT 1")
+        .await
+        .expect_err("should fail");
+
+    // THEN: The standard ErrorResponse fields should be populated
+    let db_err = err.as_db_error().expect("should be a database error");
+    assert!(!db_err.message().is_empty(), "message 'M' should be present");
+    assert!(
+        !db_err.severity().is_empty(),
+        "severity 'S'/'V' should be present"
+    );
+    assert!(
+        db_err.code().code().len() == 5,
+        "SQLSTATE 'C' should be five characters: {:?}",
+        db_err.code()
+    );
+}
@@ -0,0 +1,174 @@
+use tokio_postgres::{AsyncMessage, Client, NoTls, Config};
+use futures::{future, stream, FutureExt, StreamExt, TryStreamExt};
+use futures::channel::mpsc;
+use std::env;
+use std::time::Duration;
+
+/// Get PostgreSQL connection configuration from environment
+fn get_connection_config() -> Config {
+    let host = env::var("PGWIRE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("PGWIRE_PORT")
+        .unwrap_or_else(|_| "5432".to_string())
+        .parse::<u16>()
+        .unwrap_or(5432);
+    let dbname = env::var("PGWIRE_DATABASE").unwrap_or_else(|_| "USER".to_string());
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    let mut config = Config::new();
+    config
+        .host(&host)
+        .port(port)
+        .dbname(&dbname)
+        .user(&user)
+        .password(&password);
+
+    config
+}
+
+/// Connect and forward asynchronous backend messages (notifications) onto a
+/// channel, following the pattern the tokio-postgres runtime tests use.
+async fn connect_with_notifications() -> (Client, mpsc::UnboundedReceiver<AsyncMessage>) {
+    let config = get_connection_config();
+    let (client, mut connection) = config
+        .connect(NoTls)
+        .await
+        .expect("should connect");
+
+    let (tx, rx) = mpsc::unbounded();
+    let stream = stream::poll_fn(move |cx| connection.poll_message(cx)).map_err(|e| panic!("{}", e));
+    let forward = stream.forward(tx).map(|r| r.unwrap());
+    tokio::spawn(forward);
+
+    (client, rx)
+}
+
+/// Drain the channel and return the (channel, payload) pair of every
+/// NotificationResponse, skipping other asynchronous messages.
+async fn collect_notifications(
+    rx: mpsc::UnboundedReceiver<AsyncMessage>,
+) -> Vec<(String, String)> {
+    rx.filter_map(|m| match m {
+        AsyncMessage::Notification(n) => {
+            future::ready(Some((n.channel().to_string(), n.payload().to_string())))
+        }
+        _ => future::ready(None),
+    })
+    .collect()
+    .await
+}
+
+#[tokio::test]
+async fn test_listen_notify_roundtrip() {
+    // GIVEN: A connection listening on a channel
+    let (client, rx) = connect_with_notifications().await;
+    client
+        .batch_execute("LISTEN test_channel")
+        .await
+        .expect("should LISTEN");
+
+    // WHEN: A NOTIFY with a payload is issued on the same connection
+    client
+        .batch_execute("NOTIFY test_channel, 'hello'")
+        .await
+        .expect("should NOTIFY");
+
+    // Allow the asynchronous NotificationResponse to arrive, then stop.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    drop(client);
+
+    // THEN: The forwarded notification should carry channel and payload
+    let notifications = collect_notifications(rx).await;
+    assert!(
+        notifications
+            .iter()
+            .any(|(ch, payload)| ch == "test_channel" && payload == "hello"),
+        "should receive a notification on test_channel: {:?}",
+        notifications
+    );
+}
+
+#[tokio::test]
+async fn test_notify_without_payload() {
+    // GIVEN: A listening connection
+    let (client, rx) = connect_with_notifications().await;
+    client.batch_execute("LISTEN bare_channel").await.expect("should LISTEN");
+
+    // WHEN: Notifying without an explicit payload
+    client.batch_execute("NOTIFY bare_channel").await.expect("should NOTIFY");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    drop(client);
+
+    // THEN: A notification with an empty payload should be delivered
+    let notifications = collect_notifications(rx).await;
+    assert!(
+        notifications
+            .iter()
+            .any(|(ch, payload)| ch == "bare_channel" && payload.is_empty()),
+        "should receive a payload-less notification: {:?}",
+        notifications
+    );
+}
+
+#[tokio::test]
+async fn test_unlisten_stops_delivery() {
+    // GIVEN: A connection that listens then unlistens
+    let (client, rx) = connect_with_notifications().await;
+    client.batch_execute("LISTEN drop_channel").await.expect("should LISTEN");
+    client.batch_execute("UNLISTEN drop_channel").await.expect("should UNLISTEN");
+
+    // WHEN: Notifying a channel that is no longer subscribed
+    client
+        .batch_execute("NOTIFY drop_channel, 'ignored'")
+        .await
+        .expect("should NOTIFY");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    drop(client);
+
+    // THEN: No notification should be delivered for the dropped channel
+    let notifications = collect_notifications(rx).await;
+    assert!(
+        !notifications.iter().any(|(ch, _)| ch == "drop_channel"),
+        "UNLISTEN should suppress delivery: {:?}",
+        notifications
+    );
+}
+
+#[tokio::test]
+async fn test_cross_connection_notify() {
+    // GIVEN: One listener connection and a separate notifier connection
+    let (listener, rx) = connect_with_notifications().await;
+    listener
+        .batch_execute("LISTEN shared_channel")
+        .await
+        .expect("should LISTEN");
+
+    let config = get_connection_config();
+    let (notifier, connection) = config.connect(NoTls).await.expect("notifier should connect");
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    // WHEN: The notifier fires on the channel the listener subscribed to
+    notifier
+        .batch_execute("NOTIFY shared_channel, 'from-peer'")
+        .await
+        .expect("should NOTIFY from a second connection");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    drop(listener);
+
+    // THEN: The listener should receive the cross-connection notification
+    let notifications = collect_notifications(rx).await;
+    assert!(
+        notifications
+            .iter()
+            .any(|(ch, payload)| ch == "shared_channel" && payload == "from-peer"),
+        "listener should see notifications from another connection: {:?}",
+        notifications
+    );
+}
@@ -53,6 +53,9 @@ async fn test_select_constant() {
     assert_eq!(result, 42);
 }
 
+// 3.14 is an intentional literal exercising float column decoding, not an
+// attempt to approximate PI.
+#[allow(clippy::approx_constant)]
 #[tokio::test]
 async fn test_select_multiple_columns() {
     // GIVEN: Connected client
@@ -0,0 +1,156 @@
+use tokio_postgres::{Client, Config};
+use tokio_postgres::config::SslMode;
+use postgres_native_tls::MakeTlsConnector;
+use native_tls::TlsConnector;
+use std::env;
+
+/// Get PostgreSQL connection configuration from environment
+fn get_connection_config() -> Config {
+    let host = env::var("PGWIRE_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("PGWIRE_PORT")
+        .unwrap_or_else(|_| "5432".to_string())
+        .parse::<u16>()
+        .unwrap_or(5432);
+    let dbname = env::var("PGWIRE_DATABASE").unwrap_or_else(|_| "USER".to_string());
+    let user = env::var("PGWIRE_USERNAME").unwrap_or_else(|_| "test_user".to_string());
+    let password = env::var("PGWIRE_PASSWORD").unwrap_or_else(|_| "test".to_string());
+
+    let mut config = Config::new();
+    config
+        .host(&host)
+        .port(port)
+        .dbname(&dbname)
+        .user(&user)
+        .password(&password);
+
+    config
+}
+
+/// Build a native-tls connector that accepts the server certificate.
+///
+/// The PGWire server presents a self-signed certificate in the test
+/// deployments, so certificate verification is relaxed here; production
+/// clients would pin the CA instead.
+fn make_tls() -> MakeTlsConnector {
+    let connector = TlsConnector::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .expect("should build TLS connector");
+    MakeTlsConnector::new(connector)
+}
+
+/// Establish a TLS connection with the given SSL mode.
+async fn connect_tls(mode: SslMode) -> Result<Client, Box<dyn std::error::Error>> {
+    let mut config = get_connection_config();
+    config.ssl_mode(mode);
+
+    let (client, connection) = config.connect(make_tls()).await?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+#[tokio::test]
+async fn test_ssl_require_handshake() {
+    // GIVEN: A client configured with SslMode::Require
+    // WHEN: Connecting, driving the SSLRequest handshake
+    let client = connect_tls(SslMode::Require)
+        .await
+        .expect("should establish TLS connection");
+
+    // THEN: The upgraded channel should carry queries normally
+    let row = client
+        .query_one("SELECT 1", &[])
+        .await
+        .expect("should execute simple query over TLS");
+
+    let result: i32 = row.get(0);
+    assert_eq!(result, 1, "should return 1 over TLS");
+}
+
+#[tokio::test]
+async fn test_ssl_prefer_handshake() {
+    // GIVEN: A client configured with SslMode::Prefer
+    // WHEN: Connecting, the server may accept ('S') or decline ('N')
+    let client = connect_tls(SslMode::Prefer)
+        .await
+        .expect("should connect with SslMode::Prefer");
+
+    // THEN: The connection should work regardless of negotiation outcome
+    let row = client
+        .query_one("SELECT 1", &[])
+        .await
+        .expect("should execute query");
+
+    let result: i32 = row.get(0);
+    assert_eq!(result, 1);
+}
+
+#[tokio::test]
+async fn test_ssl_multiple_queries() {
+    // GIVEN: An encrypted connection
+    let client = connect_tls(SslMode::Require)
+        .await
+        .expect("should connect over TLS");
+
+    // WHEN: Executing several queries on the encrypted channel
+    let row1 = client.query_one("SELECT 'hello'", &[]).await.expect("query 1");
+    let row2 = client.query_one("SELECT 'world'", &[]).await.expect("query 2");
+
+    // THEN: All queries should round-trip correctly
+    let result1: String = row1.get(0);
+    let result2: String = row2.get(0);
+    assert_eq!(result1, "hello");
+    assert_eq!(result2, "world");
+}
+
+#[tokio::test]
+async fn test_ssl_disable_falls_back_to_plain() {
+    // GIVEN: A client that explicitly disables SSL
+    let mut config = get_connection_config();
+    config.ssl_mode(SslMode::Disable);
+
+    // WHEN: Connecting without sending an SSLRequest packet
+    let (client, connection) = config
+        .connect(make_tls())
+        .await
+        .expect("should connect in plaintext when SSL is disabled");
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {}", e);
+        }
+    });
+
+    // THEN: Queries should still succeed on the plaintext channel
+    let row = client.query_one("SELECT 42", &[]).await.expect("should query");
+    let result: i32 = row.get(0);
+    assert_eq!(result, 42);
+}
+
+#[tokio::test]
+async fn test_ssl_server_version_over_tls() {
+    // GIVEN: An encrypted connection
+    let client = connect_tls(SslMode::Require)
+        .await
+        .expect("should connect over TLS");
+
+    // WHEN: Querying server version through the TLS channel
+    let row = client
+        .query_one("SELECT version()", &[])
+        .await
+        .expect("should get version over TLS");
+
+    // THEN: Should return version string
+    let version: String = row.get(0);
+    assert!(
+        version.contains("PostgreSQL") || version.contains("IRIS"),
+        "Version should mention PostgreSQL or IRIS: {}",
+        version
+    );
+}
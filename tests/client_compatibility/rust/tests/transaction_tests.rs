@@ -1,4 +1,5 @@
 use tokio_postgres::{Client, NoTls, Config};
+use tokio_postgres::IsolationLevel;
 use std::env;
 
 fn get_connection_config() -> Config {
@@ -114,3 +115,110 @@ async fn test_multiple_queries_in_transaction() {
     assert_eq!(row2.get::<_, i32>(0), 2);
     assert_eq!(row3.get::<_, i32>(0), 3);
 }
+
+// Note: isolation-level and read-only transactions exercise the query
+// translation layer's handling of `BEGIN ISOLATION LEVEL ...` and
+// `SET TRANSACTION ...`. The builder tests go through tokio-postgres'
+// transaction builder, which sends the equivalent
+// `START TRANSACTION ISOLATION LEVEL ...[, READ ONLY]` form instead.
+
+#[tokio::test]
+async fn test_begin_isolation_level_read_committed() {
+    // GIVEN: Connected client
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Issuing BEGIN ISOLATION LEVEL READ COMMITTED directly
+    let result = client
+        .batch_execute("BEGIN ISOLATION LEVEL READ COMMITTED; COMMIT")
+        .await;
+
+    // THEN: The statement should be recognized and translated
+    assert!(result.is_ok(), "READ COMMITTED isolation should be accepted");
+}
+
+#[tokio::test]
+async fn test_begin_isolation_level_serializable() {
+    // GIVEN: Connected client
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Issuing BEGIN ISOLATION LEVEL SERIALIZABLE
+    let result = client
+        .batch_execute("BEGIN ISOLATION LEVEL SERIALIZABLE; COMMIT")
+        .await;
+
+    // THEN: The highest isolation level should be accepted
+    assert!(result.is_ok(), "SERIALIZABLE isolation should be accepted");
+}
+
+#[tokio::test]
+async fn test_transaction_builder_repeatable_read() {
+    // GIVEN: A mutable client and tokio-postgres' transaction builder
+    let mut client = connect().await.expect("should connect");
+
+    // WHEN: Starting a transaction at REPEATABLE READ via the builder
+    let txn = client
+        .build_transaction()
+        .isolation_level(IsolationLevel::RepeatableRead)
+        .start()
+        .await
+        .expect("should start REPEATABLE READ transaction");
+
+    let row = txn.query_one("SELECT 1", &[]).await.expect("should query");
+    assert_eq!(row.get::<_, i32>(0), 1);
+
+    // THEN: The transaction commits cleanly
+    txn.commit().await.expect("should commit");
+}
+
+#[tokio::test]
+async fn test_transaction_builder_read_only() {
+    // GIVEN: A mutable client
+    let mut client = connect().await.expect("should connect");
+
+    // WHEN: Starting a READ ONLY SERIALIZABLE transaction via the builder
+    let txn = client
+        .build_transaction()
+        .isolation_level(IsolationLevel::Serializable)
+        .read_only(true)
+        .start()
+        .await
+        .expect("should start read-only transaction");
+
+    let row = txn.query_one("SELECT 1", &[]).await.expect("should query");
+    assert_eq!(row.get::<_, i32>(0), 1);
+
+    // THEN: A read-only transaction commits cleanly
+    txn.commit().await.expect("should commit");
+}
+
+#[tokio::test]
+async fn test_set_transaction_statement() {
+    // GIVEN: Connected client inside a transaction
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Adjusting the characteristics of the current transaction
+    let result = client
+        .batch_execute(
+            "BEGIN; SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ WRITE; COMMIT",
+        )
+        .await;
+
+    // THEN: The standalone SET TRANSACTION statement should be accepted
+    assert!(result.is_ok(), "SET TRANSACTION should be accepted");
+}
+
+#[tokio::test]
+async fn test_savepoint_and_rollback_to() {
+    // GIVEN: A client in a transaction
+    let client = connect().await.expect("should connect");
+
+    // WHEN: Establishing a savepoint and rolling back to it
+    let result = client
+        .batch_execute(
+            "BEGIN; SAVEPOINT sp1; SELECT 1; ROLLBACK TO SAVEPOINT sp1; COMMIT",
+        )
+        .await;
+
+    // THEN: Nested transaction points should be expressible
+    assert!(result.is_ok(), "SAVEPOINT / ROLLBACK TO should be accepted");
+}